@@ -42,9 +42,14 @@
 #![warn(unused_crate_dependencies)]
 
 use std::{
-    ffi::{CStr, CString},
+    ffi::{CStr, CString, OsStr},
+    fmt,
     io::{Error, Result},
-    mem, ptr,
+    mem,
+    os::unix::ffi::OsStrExt,
+    path::Path,
+    ptr,
+    sync::{Mutex, MutexGuard},
 };
 
 /// Representation of a user information stored in the password file `/etc/passwd`.
@@ -85,6 +90,146 @@ impl Passwd {
         Self::from_uid(unsafe { libc::getuid() })
     }
 
+    /// Returns an iterator over every entry in the password file.
+    ///
+    /// This holds a process-wide lock for as long as the returned iterator is alive,
+    /// since the underlying `setpwent`/`getpwent`/`endpwent` cursor is shared, global
+    /// process state and is not safe to traverse from two threads at once.
+    pub fn all() -> AllUsers {
+        AllUsers::new()
+    }
+
+    /// Returns the username as an [`OsStr`], without requiring it to be valid UTF-8.
+    pub fn name_os(&self) -> &OsStr {
+        OsStr::from_bytes(self.name.as_bytes())
+    }
+
+    /// Returns the home directory as a [`Path`], without requiring it to be valid UTF-8.
+    ///
+    /// This borrows from [`dir`](Self::dir) and can be passed straight to `std::fs` without a
+    /// fallible UTF-8 conversion.
+    pub fn dir_path(&self) -> &Path {
+        Path::new(OsStr::from_bytes(self.dir.as_bytes()))
+    }
+
+    /// Returns the login shell as a [`Path`], without requiring it to be valid UTF-8.
+    ///
+    /// This borrows from [`shell`](Self::shell) and can be passed straight to `std::process::Command`
+    /// without a fallible UTF-8 conversion.
+    pub fn shell_path(&self) -> &Path {
+        Path::new(OsStr::from_bytes(self.shell.as_bytes()))
+    }
+
+    /// Parses the `gecos` field into its conventional comma-separated sub-fields.
+    ///
+    /// See [`Gecos`] for the field layout. The raw [`gecos`](Self::gecos) field is left
+    /// untouched for callers who want it unparsed.
+    pub fn gecos_fields(&self) -> Gecos<'_> {
+        let gecos = self.gecos.to_str().unwrap_or_default();
+        let mut fields = gecos.splitn(5, ',');
+        let full_name = fields
+            .next()
+            .filter(|s| !s.is_empty())
+            .map(|s| self.expand_gecos_name(s));
+        let office = fields.next().filter(|s| !s.is_empty());
+        let office_phone = fields.next().filter(|s| !s.is_empty());
+        let home_phone = fields.next().filter(|s| !s.is_empty());
+        let other = fields.next().filter(|s| !s.is_empty());
+
+        Gecos {
+            full_name,
+            office,
+            office_phone,
+            home_phone,
+            other,
+        }
+    }
+
+    /// Expands a literal `&` in the full-name gecos sub-field to the capitalized login name,
+    /// the classic finger(1) convention.
+    fn expand_gecos_name(&self, full_name: &str) -> String {
+        if !full_name.contains('&') {
+            return full_name.to_owned();
+        }
+
+        let login = self.name.to_str().unwrap_or_default();
+        let mut chars = login.chars();
+        let capitalized = match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect::<String>(),
+            None => String::new(),
+        };
+        full_name.replace('&', &capitalized)
+    }
+
+    /// Resolves the full set of group IDs this user belongs to: the primary [`gid`](Self::gid)
+    /// plus every supplementary group, mirroring what `id` prints.
+    pub fn groups(&self) -> Result<Vec<libc::gid_t>> {
+        let mut ngroups: libc::c_int = 16;
+
+        loop {
+            let mut buf: Vec<libc::gid_t> = vec![0; ngroups as usize];
+            let prev_ngroups = ngroups;
+            let ret = unsafe {
+                libc::getgrouplist(self.name.as_ptr(), self.gid, buf.as_mut_ptr(), &mut ngroups)
+            };
+
+            if ret >= 0 {
+                buf.truncate(ngroups as usize);
+                return Ok(buf);
+            }
+
+            // The buffer was too small; `ngroups` now holds the required size. Retry with it.
+            if ngroups <= prev_ngroups {
+                ngroups = prev_ngroups * 2;
+            }
+        }
+    }
+
+    /// Resolves every group ID from [`Self::groups`] into a [`Group`], skipping any that no
+    /// longer exist.
+    pub fn resolved_groups(&self) -> Result<Vec<Group>> {
+        self.groups()?
+            .into_iter()
+            .filter_map(|gid| Group::from_gid(gid).transpose())
+            .collect()
+    }
+
+    /// Drops the current process's privileges to this user.
+    ///
+    /// The steps are applied in the only order that is safe: supplementary groups via
+    /// `initgroups`, then the group ID via `setgid`, then the user ID via `setuid`. Reversing
+    /// this order leaves residual privilege, since `setuid` would fail to relinquish root
+    /// while the process still holds root's group memberships, and `setgid` requires root
+    /// privilege that `setuid` has already given up. After `setuid`, this verifies that
+    /// `getuid()` and `geteuid()` both match the target uid, so a failed drop cannot be
+    /// silently ignored.
+    pub fn switch_to(&self) -> Result<()> {
+        // SAFETY: `self.name` is a NUL-terminated `CString`, valid for the duration of the call.
+        let ret = unsafe { libc::initgroups(self.name.as_ptr(), self.gid) };
+        if ret != 0 {
+            return Err(Error::last_os_error());
+        }
+
+        let ret = unsafe { libc::setgid(self.gid) };
+        if ret != 0 {
+            return Err(Error::last_os_error());
+        }
+
+        let ret = unsafe { libc::setuid(self.uid) };
+        if ret != 0 {
+            return Err(Error::last_os_error());
+        }
+
+        let (uid, euid) = unsafe { (libc::getuid(), libc::geteuid()) };
+        if uid != self.uid || euid != self.uid {
+            return Err(Error::other(
+                "failed to fully drop privileges to the target user",
+            ));
+        }
+
+        Ok(())
+    }
+
     unsafe fn from_c_struct(passwd: &libc::passwd) -> Self {
         let libc::passwd {
             pw_name,
@@ -107,6 +252,241 @@ impl Passwd {
     }
 }
 
+/// The parsed sub-fields of a [`Passwd::gecos`] comment, returned by [`Passwd::gecos_fields`].
+///
+/// The `gecos` field conventionally holds up to five comma-separated sub-fields: full name,
+/// office room, office phone, home phone, and other. Empty sub-fields are reported as `None`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Gecos<'a> {
+    /// The user's full name, with a literal `&` expanded to the capitalized login name.
+    pub full_name: Option<String>,
+    /// The user's office room number.
+    pub office: Option<&'a str>,
+    /// The user's office phone number.
+    pub office_phone: Option<&'a str>,
+    /// The user's home phone number.
+    pub home_phone: Option<&'a str>,
+    /// Any remaining, non-standard sub-fields.
+    pub other: Option<&'a str>,
+}
+
+/// Representation of a group information stored in the group file `/etc/group`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Group {
+    /// A group name.
+    pub name: CString,
+    /// A group password.
+    pub passwd: CString,
+    /// A group ID.
+    pub gid: libc::gid_t,
+    /// The usernames of the members of the group.
+    pub members: Vec<CString>,
+}
+
+impl Group {
+    /// Looks up the group name in the group file and returns a `Group` with group information, if the group is found.
+    pub fn from_name(name: impl AsRef<CStr>) -> Result<Option<Self>> {
+        let name = name.as_ref();
+        getgr_r(name.as_ptr(), libc::getgrnam_r)
+    }
+
+    /// Looks up the group ID and returns a `Group` with group information, if the group is found.
+    pub fn from_gid(gid: libc::gid_t) -> Result<Option<Self>> {
+        getgr_r(gid, libc::getgrgid_r)
+    }
+
+    /// Looks up current process's group information in the group file and return a `Group` with group information, if the group is found.
+    ///
+    /// This is a shortcut for `Group::from_gid(libc::getgid())`.
+    pub fn current_group() -> Result<Option<Self>> {
+        Self::from_gid(unsafe { libc::getgid() })
+    }
+
+    /// Returns an iterator over every entry in the group file.
+    ///
+    /// This holds a process-wide lock for as long as the returned iterator is alive,
+    /// since the underlying `setgrent`/`getgrent`/`endgrent` cursor is shared, global
+    /// process state and is not safe to traverse from two threads at once.
+    pub fn all() -> AllGroups {
+        AllGroups::new()
+    }
+
+    unsafe fn from_c_struct(group: &libc::group) -> Self {
+        let libc::group {
+            gr_name,
+            gr_passwd,
+            gr_gid,
+            gr_mem,
+        } = *group;
+        let mut members = Vec::new();
+        let mut p = gr_mem;
+        while !(*p).is_null() {
+            members.push(CStr::from_ptr(*p).to_owned());
+            p = p.add(1);
+        }
+        Self {
+            name: CStr::from_ptr(gr_name).to_owned(),
+            passwd: CStr::from_ptr(gr_passwd).to_owned(),
+            gid: gr_gid,
+            members,
+        }
+    }
+}
+
+/// Representation of a shadow password entry stored in the shadow password file `/etc/shadow`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Shadow {
+    /// A username.
+    pub name: CString,
+    /// An encrypted password, or a status string such as `!` or `*`.
+    pub passwd: CString,
+    /// The date of the last password change, in days since the Epoch.
+    pub last_change: libc::c_long,
+    /// The minimum number of days before the password may be changed again.
+    pub min: libc::c_long,
+    /// The number of days after which the password must be changed.
+    pub max: libc::c_long,
+    /// The number of days before expiration that the user is warned.
+    pub warn: libc::c_long,
+    /// The number of days after expiration that the account is disabled.
+    pub inactive: libc::c_long,
+    /// The date on which the account expires, in days since the Epoch.
+    pub expire: libc::c_long,
+}
+
+impl Shadow {
+    /// Looks up the username in the shadow password file and returns a `Shadow` with shadow
+    /// information, if the user is found.
+    ///
+    /// Reading `/etc/shadow` requires privilege; if the calling process is not permitted to
+    /// read it, this returns [`ShadowError::PermissionDenied`] rather than `Ok(None)`, so
+    /// callers can tell "not permitted" apart from "no such user".
+    pub fn from_name(name: impl AsRef<CStr>) -> std::result::Result<Option<Self>, ShadowError> {
+        let name = name.as_ref();
+        getsp_r(name.as_ptr(), libc::getspnam_r)
+    }
+
+    unsafe fn from_c_struct(shadow: &libc::spwd) -> Self {
+        let libc::spwd {
+            sp_namp,
+            sp_pwdp,
+            sp_lstchg,
+            sp_min,
+            sp_max,
+            sp_warn,
+            sp_inact,
+            sp_expire,
+            ..
+        } = *shadow;
+        Self {
+            name: CStr::from_ptr(sp_namp).to_owned(),
+            passwd: CStr::from_ptr(sp_pwdp).to_owned(),
+            last_change: sp_lstchg,
+            min: sp_min,
+            max: sp_max,
+            warn: sp_warn,
+            inactive: sp_inact,
+            expire: sp_expire,
+        }
+    }
+}
+
+/// Error returned by [`Shadow::from_name`].
+#[derive(Debug)]
+pub enum ShadowError {
+    /// The calling process does not have permission to read the shadow password file.
+    PermissionDenied,
+    /// Some other I/O error occurred.
+    Io(Error),
+}
+
+impl fmt::Display for ShadowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PermissionDenied => {
+                write!(f, "permission denied while reading the shadow password file")
+            }
+            Self::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ShadowError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::PermissionDenied => None,
+            Self::Io(e) => Some(e),
+        }
+    }
+}
+
+impl From<ShadowError> for Error {
+    fn from(e: ShadowError) -> Self {
+        match e {
+            ShadowError::PermissionDenied => Error::from_raw_os_error(libc::EACCES),
+            ShadowError::Io(e) => e,
+        }
+    }
+}
+
+/// An iterator over every entry in the password file, created by [`Passwd::all`].
+pub struct AllUsers {
+    _guard: MutexGuard<'static, ()>,
+}
+
+static PWENT_LOCK: Mutex<()> = Mutex::new(());
+
+impl AllUsers {
+    fn new() -> Self {
+        let guard = PWENT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { libc::setpwent() };
+        Self { _guard: guard }
+    }
+}
+
+impl Iterator for AllUsers {
+    type Item = Result<Passwd>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        getpwent_r().transpose()
+    }
+}
+
+impl Drop for AllUsers {
+    fn drop(&mut self) {
+        unsafe { libc::endpwent() };
+    }
+}
+
+/// An iterator over every entry in the group file, created by [`Group::all`].
+pub struct AllGroups {
+    _guard: MutexGuard<'static, ()>,
+}
+
+static GRENT_LOCK: Mutex<()> = Mutex::new(());
+
+impl AllGroups {
+    fn new() -> Self {
+        let guard = GRENT_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        unsafe { libc::setgrent() };
+        Self { _guard: guard }
+    }
+}
+
+impl Iterator for AllGroups {
+    type Item = Result<Group>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        getgrent_r().transpose()
+    }
+}
+
+impl Drop for AllGroups {
+    fn drop(&mut self) {
+        unsafe { libc::endgrent() };
+    }
+}
+
 fn getpw_r<T>(
     key: T,
     f: unsafe extern "C" fn(
@@ -120,31 +500,155 @@ fn getpw_r<T>(
 where
     T: Copy,
 {
-    let mut passwd = unsafe { mem::zeroed() };
-    let amt = unsafe { libc::sysconf(libc::_SC_GETPW_R_SIZE_MAX) };
-    let mut amt = libc::c_long::max(amt, 512) as usize;
+    getxx_r(key, libc::_SC_GETPW_R_SIZE_MAX, f, |p| unsafe {
+        Passwd::from_c_struct(p)
+    })
+}
+
+fn getgr_r<T>(
+    key: T,
+    f: unsafe extern "C" fn(
+        key: T,
+        grp: *mut libc::group,
+        buf: *mut libc::c_char,
+        buflen: libc::size_t,
+        result: *mut *mut libc::group,
+    ) -> libc::c_int,
+) -> Result<Option<Group>>
+where
+    T: Copy,
+{
+    getxx_r(key, libc::_SC_GETGR_R_SIZE_MAX, f, |g| unsafe {
+        Group::from_c_struct(g)
+    })
+}
+
+/// Generic `*_r`-style lookup with the ERANGE/EINTR retry loop shared by `getpwnam_r`/`getpwuid_r`
+/// and `getgrnam_r`/`getgrgid_r`.
+fn getxx_r<K, C, T>(
+    key: K,
+    sysconf_name: libc::c_int,
+    f: unsafe extern "C" fn(
+        key: K,
+        entry: *mut C,
+        buf: *mut libc::c_char,
+        buflen: libc::size_t,
+        result: *mut *mut C,
+    ) -> libc::c_int,
+    from_c_struct: impl Fn(&C) -> T,
+) -> Result<Option<T>>
+where
+    K: Copy,
+{
+    let amt = sysconf_amt(sysconf_name);
+    match retry_r(
+        amt,
+        |entry, buf, buflen, result| unsafe { f(key, entry, buf, buflen, result) },
+        from_c_struct,
+    ) {
+        Ok(v) => Ok(v),
+        // The given name, uid, or gid was not found.
+        // see https://man7.org/linux/man-pages/man3/getpwnam_r.3.html
+        // see https://man7.org/linux/man-pages/man3/getgrnam_r.3.html
+        Err(0 | libc::ENOENT | libc::ESRCH | libc::EBADF | libc::EPERM) => Ok(None),
+        Err(errno) => Err(Error::from_raw_os_error(errno)),
+    }
+}
+
+/// Reads the next entry from the shared `getpwent` cursor, started by [`AllUsers::new`].
+fn getpwent_r() -> Result<Option<Passwd>> {
+    let amt = sysconf_amt(libc::_SC_GETPW_R_SIZE_MAX);
+    match retry_r(
+        amt,
+        |entry, buf, buflen, result| unsafe { libc::getpwent_r(entry, buf, buflen, result) },
+        |p| unsafe { Passwd::from_c_struct(p) },
+    ) {
+        Ok(v) => Ok(v),
+        // The cursor has been exhausted.
+        // see https://man7.org/linux/man-pages/man3/getpwent_r.3.html
+        Err(0 | libc::ENOENT | libc::ESRCH) => Ok(None),
+        Err(errno) => Err(Error::from_raw_os_error(errno)),
+    }
+}
+
+/// Reads the next entry from the shared `getgrent` cursor, started by [`AllGroups::new`].
+fn getgrent_r() -> Result<Option<Group>> {
+    let amt = sysconf_amt(libc::_SC_GETGR_R_SIZE_MAX);
+    match retry_r(
+        amt,
+        |entry, buf, buflen, result| unsafe { libc::getgrent_r(entry, buf, buflen, result) },
+        |g| unsafe { Group::from_c_struct(g) },
+    ) {
+        Ok(v) => Ok(v),
+        // The cursor has been exhausted.
+        // see https://man7.org/linux/man-pages/man3/getgrent_r.3.html
+        Err(0 | libc::ENOENT | libc::ESRCH) => Ok(None),
+        Err(errno) => Err(Error::from_raw_os_error(errno)),
+    }
+}
+
+fn getsp_r<T>(
+    key: T,
+    f: unsafe extern "C" fn(
+        key: T,
+        pwd: *mut libc::spwd,
+        buf: *mut libc::c_char,
+        buflen: libc::size_t,
+        result: *mut *mut libc::spwd,
+    ) -> libc::c_int,
+) -> std::result::Result<Option<Shadow>, ShadowError>
+where
+    T: Copy,
+{
+    // glibc has no `sysconf` name for the shadow buffer size; 1024 bytes is the conventional
+    // starting point and the ERANGE retry grows it from there.
+    match retry_r(
+        1024,
+        |entry, buf, buflen, result| unsafe { f(key, entry, buf, buflen, result) },
+        |s| unsafe { Shadow::from_c_struct(s) },
+    ) {
+        Ok(v) => Ok(v),
+        // The given name was not found.
+        // see https://man7.org/linux/man-pages/man3/getspnam_r.3.html
+        Err(0 | libc::ENOENT | libc::ESRCH) => Ok(None),
+        // Reading the shadow file requires privilege.
+        Err(libc::EACCES | libc::EPERM) => Err(ShadowError::PermissionDenied),
+        Err(errno) => Err(ShadowError::Io(Error::from_raw_os_error(errno))),
+    }
+}
+
+fn sysconf_amt(sysconf_name: libc::c_int) -> usize {
+    let amt = unsafe { libc::sysconf(sysconf_name) };
+    libc::c_long::max(amt, 512) as usize
+}
+
+/// Core ERANGE/EINTR retry loop shared by every `*_r`-style lookup in this crate, whether keyed
+/// (`getpwnam_r`, `getgrgid_r`, ...) or cursor-based (`getpwent_r`, `getgrent_r`).
+///
+/// On success, returns `Ok(Some(_))`. `EINTR` and `ERANGE` are retried with no limit on the
+/// number of attempts (doubling the buffer on `ERANGE`). Any other errno is returned as
+/// `Err(errno)`, leaving interpreting it (not found? permission denied? some other I/O
+/// error?) to the caller, since that varies by lookup.
+fn retry_r<C, T>(
+    initial_amt: usize,
+    call: impl Fn(*mut C, *mut libc::c_char, libc::size_t, *mut *mut C) -> libc::c_int,
+    from_c_struct: impl Fn(&C) -> T,
+) -> std::result::Result<Option<T>, libc::c_int> {
+    let mut entry: C = unsafe { mem::zeroed() };
+    let mut amt = initial_amt;
     let mut buf = Vec::with_capacity(amt);
 
     loop {
         buf.reserve(amt);
         let mut result = ptr::null_mut();
-        unsafe {
-            f(
-                key,
-                &mut passwd,
-                buf.as_mut_ptr(),
-                buf.capacity(),
-                &mut result,
-            );
-        }
+        call(&mut entry, buf.as_mut_ptr(), buf.capacity(), &mut result);
 
         if !result.is_null() {
             // Success
-            return Ok(Some(unsafe { Passwd::from_c_struct(&passwd) }));
+            return Ok(Some(from_c_struct(&entry)));
         }
 
-        let e = Error::last_os_error();
-        let errno = e.raw_os_error().unwrap();
+        let errno = Error::last_os_error().raw_os_error().unwrap();
         match errno {
             // A signal was caught
             libc::EINTR => continue,
@@ -155,12 +659,8 @@ where
                 continue;
             }
 
-            // The given name or uid was not found.
-            // see https://man7.org/linux/man-pages/man3/getpwnam_r.3.html
-            0 | libc::ENOENT | libc::ESRCH | libc::EBADF | libc::EPERM => return Ok(None),
-
-            // Other errors
-            _ => return Err(e),
+            // Other errors, left for the caller to interpret
+            errno => return Err(errno),
         }
     }
 }
@@ -207,6 +707,121 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn root_os_str_accessors() -> Result<()> {
+        let root = Passwd::from_name(CString::new("root")?)?.unwrap();
+
+        assert_eq!(root.name_os(), std::ffi::OsStr::new("root"));
+        assert_eq!(root.dir_path(), std::path::Path::new("/root"));
+        assert_eq!(root.shell_path(), std::path::Path::new(root.shell.to_str()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn current_user_groups() -> Result<()> {
+        let passwd = Passwd::current_user()?.unwrap();
+        let groups = passwd.groups()?;
+        assert!(groups.contains(&passwd.gid));
+
+        let resolved = passwd.resolved_groups()?;
+        assert!(resolved.iter().any(|group| group.gid == passwd.gid));
+
+        Ok(())
+    }
+
+    #[test]
+    fn gecos_fields() -> Result<()> {
+        let mut passwd = Passwd::from_name(CString::new("root")?)?.unwrap();
+
+        passwd.gecos = CString::new("")?;
+        let gecos = passwd.gecos_fields();
+        assert_eq!(gecos.full_name, None);
+        assert_eq!(gecos.office, None);
+        assert_eq!(gecos.office_phone, None);
+        assert_eq!(gecos.home_phone, None);
+        assert_eq!(gecos.other, None);
+
+        passwd.gecos = CString::new("Full Name,Room 1,555-1234,555-5678,misc")?;
+        let gecos = passwd.gecos_fields();
+        assert_eq!(gecos.full_name.as_deref(), Some("Full Name"));
+        assert_eq!(gecos.office, Some("Room 1"));
+        assert_eq!(gecos.office_phone, Some("555-1234"));
+        assert_eq!(gecos.home_phone, Some("555-5678"));
+        assert_eq!(gecos.other, Some("misc"));
+
+        passwd.name = CString::new("root")?;
+        passwd.gecos = CString::new("& Superuser")?;
+        let gecos = passwd.gecos_fields();
+        assert_eq!(gecos.full_name.as_deref(), Some("Root Superuser"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn root_shadow() -> Result<()> {
+        match Shadow::from_name(CString::new("root")?) {
+            Ok(Some(shadow)) => assert_eq!(shadow.name.to_str()?, "root"),
+            Ok(None) => panic!("root should have a shadow entry"),
+            // Unprivileged test runs cannot read /etc/shadow; that's a valid outcome too.
+            Err(ShadowError::PermissionDenied) => {}
+            Err(e @ ShadowError::Io(_)) => return Err(e.into()),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn all_users_contains_root() -> Result<()> {
+        let found = Passwd::all()
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .any(|passwd| passwd.uid == 0);
+        assert!(found);
+        Ok(())
+    }
+
+    #[test]
+    fn root_group() -> Result<()> {
+        let by_name = Group::from_name(CString::new("root")?)?.unwrap();
+        let by_gid = Group::from_gid(0)?.unwrap();
+
+        assert_eq!(by_name.gid, 0);
+        assert_eq!(by_name.name.to_str()?, "root");
+
+        assert_eq!(by_gid, by_name);
+
+        Ok(())
+    }
+
+    #[test]
+    fn current_group() -> Result<()> {
+        let gid = unsafe { libc::getgid() };
+        let by_cg = Group::current_group()?.unwrap();
+        let by_gid = Group::from_gid(gid)?.unwrap();
+
+        assert_eq!(by_cg.gid, gid);
+        assert_eq!(by_cg, by_gid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn all_groups_contains_root() -> Result<()> {
+        let found = Group::all()
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .into_iter()
+            .any(|group| group.gid == 0);
+        assert!(found);
+        Ok(())
+    }
+
+    #[test]
+    fn group_not_exist() -> Result<()> {
+        assert!(Group::from_gid(u32::MAX)?.is_none());
+        assert!(Group::from_name(CString::new("")?)?.is_none());
+        Ok(())
+    }
+
     #[test]
     fn test_readme_deps() {
         version_sync::assert_markdown_deps_updated!("README.md");